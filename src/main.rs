@@ -1,43 +1,114 @@
 use anyhow;
 use crossterm::{
+    cursor,
     event::{self, Event, KeyCode},
-    terminal,
+    terminal::{self, Clear, ClearType},
+    QueueableCommand,
 };
-use rodio::{Decoder, OutputStream, Sink};
+use rand::seq::SliceRandom;
+use rodio::{Decoder, OutputStream, Sink, Source};
 use std::env;
 use std::fs::File;
-use std::io::BufReader;
+use std::io::{self, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Number of lines the live status region occupies, so redraws know how
+/// far to rewind the cursor.
+const STATUS_LINES: u16 = 3;
+
+/// Width, in characters, of the `[====    ]` progress bar.
+const BAR_WIDTH: usize = 30;
+
+/// How far a single Left/Right press seeks, in seconds.
+const SEEK_STEP: Duration = Duration::from_secs(5);
+
+/// Extensions we recognize as playable audio when walking a directory.
+const AUDIO_EXTENSIONS: &[&str] = &["mp3", "flac", "wav", "ogg"];
+
+/// Step size for each '+'/'-' volume press.
+const VOLUME_STEP: f32 = 0.1;
+const VOLUME_RANGE: std::ops::RangeInclusive<f32> = 0.0..=2.0;
+
+/// Step size for each '['/']' speed press.
+const SPEED_STEP: f32 = 0.1;
+const SPEED_RANGE: std::ops::RangeInclusive<f32> = 0.1..=3.0;
+
+/// Cycles through how the player reacts once a track finishes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RepeatMode {
+    Off,
+    RepeatOne,
+    RepeatAll,
+}
+
+impl RepeatMode {
+    /// Advances to the next mode in the Off -> RepeatOne -> RepeatAll -> Off cycle.
+    fn next(self) -> Self {
+        match self {
+            RepeatMode::Off => RepeatMode::RepeatOne,
+            RepeatMode::RepeatOne => RepeatMode::RepeatAll,
+            RepeatMode::RepeatAll => RepeatMode::Off,
+        }
+    }
+}
+
+impl std::fmt::Display for RepeatMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RepeatMode::Off => write!(f, "off"),
+            RepeatMode::RepeatOne => write!(f, "repeat-one"),
+            RepeatMode::RepeatAll => write!(f, "repeat-all"),
+        }
+    }
+}
 
 fn main() -> anyhow::Result<()> {
     // Setup terminal in raw mode to capture key presses instantly
     terminal::enable_raw_mode()?;
 
-    // Get the music file path from command line argument
+    // Get the music file/directory path from command line argument
     let mut args = env::args().skip(1);
     let music_path = match args.next() {
         Some(path) => path,
         None => {
-            eprintln!("Usage: audix <music_file>");
+            eprintln!("Usage: audix <music_file_or_directory>");
             terminal::disable_raw_mode()?;
             std::process::exit(1);
         }
     };
 
+    let path = PathBuf::from(&music_path);
+    let mut playlist = if path.is_dir() {
+        collect_audio_files(&path)
+    } else {
+        vec![path]
+    };
+
+    if playlist.is_empty() {
+        eprintln!("No audio files found at {}", music_path);
+        terminal::disable_raw_mode()?;
+        std::process::exit(1);
+    }
+
     // Set up audio output stream and sink
     let (_stream, stream_handle) = OutputStream::try_default()?;
-    let sink = Sink::try_new(&stream_handle)?;
+    let mut sink = Sink::try_new(&stream_handle)?;
 
-    // Open music file
-    let file = File::open(&music_path)?;
-    let source = Decoder::new(BufReader::new(file))?;
+    let mut current = 0;
+    let mut duration = load_track(&sink, &playlist[current])?;
+    let mut shuffle = false;
+    let mut volume: f32 = 1.0;
+    let mut speed: f32 = 1.0;
+    let mut repeat_mode = RepeatMode::Off;
 
-    sink.append(source);
-    sink.play();
-
-    println!(
-        "Playing {}. Press SPACE to toggle pause/play. Press 'q' to quit.",
-        music_path
-    );
+    let mut stdout = io::stdout();
+    write!(
+        stdout,
+        "audix -- SPACE pause, LEFT/RIGHT seek, n/p skip, s shuffle, +/- volume, [/] speed, r repeat, q quit\r\n"
+    )?;
+    stdout.flush()?;
+    let mut redraw = false;
 
     // Main event loop for keyboard input
     loop {
@@ -48,12 +119,67 @@ fn main() -> anyhow::Result<()> {
                     KeyCode::Char(' ') => {
                         if sink.is_paused() {
                             sink.play();
-                            println!("Resumed");
                         } else {
                             sink.pause();
-                            println!("Paused");
                         }
                     }
+                    KeyCode::Left => {
+                        let target = source_position(&sink, speed).saturating_sub(SEEK_STEP);
+                        seek_to(&sink, target, duration);
+                    }
+                    KeyCode::Right => {
+                        let target = source_position(&sink, speed) + SEEK_STEP;
+                        seek_to(&sink, target, duration);
+                    }
+                    KeyCode::Char('n') => {
+                        current = (current + 1) % playlist.len();
+                        sink.stop();
+                        sink = Sink::try_new(&stream_handle)?;
+                        sink.set_volume(volume);
+                        sink.set_speed(speed);
+                        duration = load_track(&sink, &playlist[current])?;
+                    }
+                    KeyCode::Char('p') => {
+                        current = if current == 0 {
+                            playlist.len() - 1
+                        } else {
+                            current - 1
+                        };
+                        sink.stop();
+                        sink = Sink::try_new(&stream_handle)?;
+                        sink.set_volume(volume);
+                        sink.set_speed(speed);
+                        duration = load_track(&sink, &playlist[current])?;
+                    }
+                    KeyCode::Char('s') => {
+                        shuffle = !shuffle;
+                        if shuffle {
+                            playlist[current + 1..].shuffle(&mut rand::thread_rng());
+                        }
+                    }
+                    KeyCode::Char('+') => {
+                        volume = (volume + VOLUME_STEP)
+                            .clamp(*VOLUME_RANGE.start(), *VOLUME_RANGE.end());
+                        sink.set_volume(volume);
+                    }
+                    KeyCode::Char('-') => {
+                        volume = (volume - VOLUME_STEP)
+                            .clamp(*VOLUME_RANGE.start(), *VOLUME_RANGE.end());
+                        sink.set_volume(volume);
+                    }
+                    KeyCode::Char('[') => {
+                        speed =
+                            (speed - SPEED_STEP).clamp(*SPEED_RANGE.start(), *SPEED_RANGE.end());
+                        sink.set_speed(speed);
+                    }
+                    KeyCode::Char(']') => {
+                        speed =
+                            (speed + SPEED_STEP).clamp(*SPEED_RANGE.start(), *SPEED_RANGE.end());
+                        sink.set_speed(speed);
+                    }
+                    KeyCode::Char('r') => {
+                        repeat_mode = repeat_mode.next();
+                    }
                     KeyCode::Char('q') => {
                         break;
                     }
@@ -62,13 +188,166 @@ fn main() -> anyhow::Result<()> {
             }
         }
 
-        // Exit if playback ended
+        // Advance automatically once the current track finishes
         if sink.empty() {
-            println!("Playback finished");
-            break;
+            match repeat_mode {
+                RepeatMode::Off if current + 1 >= playlist.len() => {
+                    break;
+                }
+                RepeatMode::Off => current += 1,
+                RepeatMode::RepeatOne => {}
+                RepeatMode::RepeatAll => current = (current + 1) % playlist.len(),
+            }
+            sink = Sink::try_new(&stream_handle)?;
+            sink.set_volume(volume);
+            sink.set_speed(speed);
+            duration = load_track(&sink, &playlist[current])?;
         }
+
+        render_status(
+            &mut stdout,
+            &playlist[current].display().to_string(),
+            source_position(&sink, speed),
+            duration,
+            volume,
+            speed,
+            repeat_mode,
+            shuffle,
+            sink.is_paused(),
+            redraw,
+        )?;
+        redraw = true;
     }
 
+    write!(stdout, "\r\nPlayback finished\r\n")?;
+    stdout.flush()?;
     terminal::disable_raw_mode()?;
     Ok(())
 }
+
+/// Redraws the single-block now-playing status in place, rewinding the
+/// cursor to overwrite the previous frame instead of scrolling the
+/// terminal.
+fn render_status(
+    stdout: &mut io::Stdout,
+    track: &str,
+    elapsed: Duration,
+    total: Option<Duration>,
+    volume: f32,
+    speed: f32,
+    repeat_mode: RepeatMode,
+    shuffle: bool,
+    paused: bool,
+    redraw: bool,
+) -> anyhow::Result<()> {
+    if redraw {
+        stdout.queue(cursor::MoveUp(STATUS_LINES))?;
+        stdout.queue(cursor::MoveToColumn(0))?;
+    }
+    stdout.queue(Clear(ClearType::FromCursorDown))?;
+
+    let state = if paused { "Paused" } else { "Playing" };
+    let bar = progress_bar(elapsed, total, BAR_WIDTH);
+    let total_str = total
+        .map(format_duration)
+        .unwrap_or_else(|| "--:--".to_string());
+
+    write!(stdout, "{state}: {track}\r\n")?;
+    write!(
+        stdout,
+        "{bar} {}/{total_str}\r\n",
+        format_duration(elapsed.min(total.unwrap_or(elapsed)))
+    )?;
+    write!(
+        stdout,
+        "Volume: {volume:.1}  Speed: {speed:.1}x  Shuffle: {}  Repeat: {repeat_mode}\r\n",
+        if shuffle { "on" } else { "off" }
+    )?;
+    stdout.flush()?;
+    Ok(())
+}
+
+/// Renders a `[====    ]`-style bar representing `elapsed / total`.
+fn progress_bar(elapsed: Duration, total: Option<Duration>, width: usize) -> String {
+    let ratio = match total {
+        Some(total) if !total.is_zero() => {
+            (elapsed.as_secs_f64() / total.as_secs_f64()).clamp(0.0, 1.0)
+        }
+        _ => 0.0,
+    };
+    let filled = (ratio * width as f64).round() as usize;
+    format!("[{}{}]", "=".repeat(filled), " ".repeat(width - filled))
+}
+
+/// Formats a [`Duration`] as `MM:SS`.
+fn format_duration(d: Duration) -> String {
+    let secs = d.as_secs();
+    format!("{:02}:{:02}", secs / 60, secs % 60)
+}
+
+/// Recursively walks `dir`, collecting every file whose extension matches
+/// [`AUDIO_EXTENSIONS`], sorted case-insensitively by path for a stable,
+/// predictable track order.
+fn collect_audio_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return files;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            files.extend(collect_audio_files(&path));
+        } else if path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| AUDIO_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+            .unwrap_or(false)
+        {
+            files.push(path);
+        }
+    }
+
+    files.sort_by(|a, b| {
+        a.to_string_lossy()
+            .to_lowercase()
+            .cmp(&b.to_string_lossy().to_lowercase())
+    });
+    files
+}
+
+/// Decodes `path` and appends it to `sink`, returning its total duration.
+fn load_track(sink: &Sink, path: &Path) -> anyhow::Result<Option<Duration>> {
+    let file = File::open(path)?;
+    let source = Decoder::new(BufReader::new(file))?;
+    let duration = source.total_duration();
+    sink.append(source);
+    sink.play();
+    Ok(duration)
+}
+
+/// Converts `sink.get_pos()` (wall-clock playback time) into the
+/// source-domain position that `Sink::try_seek` expects, by scaling out
+/// the current playback speed.
+fn source_position(sink: &Sink, speed: f32) -> Duration {
+    sink.get_pos().mul_f32(speed)
+}
+
+/// Seeks `sink` to `target`, clamped to `[0, duration]`.
+///
+/// Mirrors rodio's own behavior: once the sink has finished playing,
+/// `try_seek` is a silent no-op.
+fn seek_to(sink: &Sink, target: Duration, duration: Option<Duration>) {
+    if sink.empty() {
+        return;
+    }
+
+    let clamped = match duration {
+        Some(total) => target.min(total),
+        None => target,
+    };
+
+    if let Err(err) = sink.try_seek(clamped) {
+        eprintln!("Seek failed: {err}");
+    }
+}